@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::error::Error;
+use crate::json::toml_to_json;
+use crate::path::{self, Segment};
+
+#[derive(Args)]
+pub struct GetArgs {
+    /// TOML file to read.
+    pub file: PathBuf,
+    /// Dotted key path to look up, e.g. `foo.bar`.
+    pub key: String,
+    /// Print a string value without JSON quoting.
+    #[arg(long, conflicts_with = "output_toml")]
+    pub raw: bool,
+    /// Exit successfully with empty output when the key is missing.
+    #[arg(long = "if-exists")]
+    pub if_exists: bool,
+    /// Print the selected table or array of tables back out as TOML instead
+    /// of JSON.
+    #[arg(long = "output-toml")]
+    pub output_toml: bool,
+}
+
+pub fn run(args: GetArgs) -> Result<(), Error> {
+    let contents = fs::read_to_string(&args.file).map_err(|source| Error::Io {
+        path: args.file.clone(),
+        source,
+    })?;
+    let document: toml::Value = contents.parse().map_err(|err: toml::de::Error| {
+        Error::ParseFailure {
+            path: args.file.clone(),
+            reason: err.to_string(),
+        }
+    })?;
+
+    let segments = path::parse(&args.key)?;
+    let value = match path::get(&document, &segments) {
+        Ok(value) => value,
+        Err(Error::KeyNotFound { .. }) if args.if_exists => return Ok(()),
+        Err(err) => return Err(err),
+    };
+
+    if args.output_toml {
+        print!("{}", reroot_as_toml(&args.file, &segments, value)?);
+    } else if args.raw {
+        match value.as_str() {
+            Some(s) => println!("{s}"),
+            None => println!("{value}"),
+        }
+    } else {
+        let json = toml_to_json(value);
+        println!("{}", serde_json::to_string(&json).unwrap());
+    }
+
+    Ok(())
+}
+
+/// Re-root a selected value under its own key, so it serializes back out as
+/// a standalone TOML fragment rather than a bare value. A path ending in an
+/// array index has no key of its own; its value is placed under `value`.
+fn reroot_as_toml(
+    file: &std::path::Path,
+    segments: &[Segment],
+    value: &toml::Value,
+) -> Result<String, Error> {
+    let name = match segments.last() {
+        Some(Segment::Key(key)) => key.clone(),
+        _ => "value".to_string(),
+    };
+    let mut table = toml::value::Table::new();
+    table.insert(name, value.clone());
+    toml::to_string(&toml::Value::Table(table)).map_err(|err| Error::ParseFailure {
+        path: file.to_path_buf(),
+        reason: err.to_string(),
+    })
+}
@@ -0,0 +1,3 @@
+pub mod get;
+pub mod rm;
+pub mod set;
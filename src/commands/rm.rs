@@ -0,0 +1,43 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::edit;
+use crate::error::Error;
+use crate::path;
+
+#[derive(Args)]
+pub struct RmArgs {
+    /// TOML file to modify.
+    pub file: PathBuf,
+    /// Dotted key path to remove, e.g. `foo.bar` or `arr[0]`.
+    pub key: String,
+    /// Print the would-be result to stdout instead of writing the file.
+    #[arg(long = "dry-run")]
+    pub dry_run: bool,
+}
+
+pub fn run(args: RmArgs) -> Result<(), Error> {
+    let contents = fs::read_to_string(&args.file).map_err(|source| Error::Io {
+        path: args.file.clone(),
+        source,
+    })?;
+    let mut document = edit::parse_document(&contents, &args.file)?;
+
+    let segments = path::parse(&args.key)?;
+    let (parents, last) = segments.split_at(segments.len() - 1);
+    let container = edit::navigate(&mut document, parents)?;
+    edit::remove(container, &last[0])?;
+
+    let rendered = document.to_string();
+    if !args.dry_run {
+        fs::write(&args.file, &rendered).map_err(|source| Error::Io {
+            path: args.file.clone(),
+            source,
+        })?;
+    }
+
+    print!("{rendered}");
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{Args, ValueEnum};
+
+use crate::edit;
+use crate::error::Error;
+use crate::json::json_to_toml;
+use crate::path;
+
+/// The TOML type to coerce a `set` argument into.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ValueType {
+    String,
+    Int,
+    Float,
+    Bool,
+    Datetime,
+    Array,
+    Table,
+}
+
+#[derive(Args)]
+pub struct SetArgs {
+    /// TOML file to modify.
+    pub file: PathBuf,
+    /// Dotted key path to write, e.g. `foo.bar`.
+    pub key: String,
+    /// Value to store. Interpreted as a plain string unless `--type` or
+    /// `--json` is given.
+    pub value: String,
+    /// Coerce the value to this TOML type instead of storing it as a string.
+    #[arg(long, value_enum, conflicts_with = "json")]
+    pub r#type: Option<ValueType>,
+    /// Parse the value as JSON and convert it to the equivalent TOML value.
+    #[arg(long)]
+    pub json: bool,
+}
+
+fn coerce(args: &SetArgs) -> Result<toml::Value, Error> {
+    if args.json {
+        let json: serde_json::Value =
+            serde_json::from_str(&args.value).map_err(|err| Error::InvalidValue {
+                reason: err.to_string(),
+            })?;
+        return json_to_toml(json);
+    }
+    match args.r#type {
+        None | Some(ValueType::String) => Ok(toml::Value::String(args.value.clone())),
+        Some(ValueType::Int) => args
+            .value
+            .parse::<i64>()
+            .map(toml::Value::Integer)
+            .map_err(|err| Error::InvalidValue { reason: err.to_string() }),
+        Some(ValueType::Float) => args
+            .value
+            .parse::<f64>()
+            .map(toml::Value::Float)
+            .map_err(|err| Error::InvalidValue { reason: err.to_string() }),
+        Some(ValueType::Bool) => args
+            .value
+            .parse::<bool>()
+            .map(toml::Value::Boolean)
+            .map_err(|err| Error::InvalidValue { reason: err.to_string() }),
+        Some(ValueType::Datetime) => toml::value::Datetime::from_str(&args.value)
+            .map(toml::Value::Datetime)
+            .map_err(|err| Error::InvalidValue { reason: err.to_string() }),
+        Some(ValueType::Array) | Some(ValueType::Table) => {
+            let fragment = format!("value = {}", args.value);
+            let parsed: toml::Value =
+                fragment.parse().map_err(|err: toml::de::Error| Error::InvalidValue {
+                    reason: err.to_string(),
+                })?;
+            let value = parsed
+                .as_table()
+                .and_then(|table| table.get("value"))
+                .cloned()
+                .expect("`value = ...` fragment always parses to a table with `value`");
+            match (args.r#type, &value) {
+                (Some(ValueType::Array), toml::Value::Array(_))
+                | (Some(ValueType::Table), toml::Value::Table(_)) => Ok(value),
+                (Some(ValueType::Array), _) => Err(Error::InvalidValue {
+                    reason: format!("`{}` is not a TOML array", args.value),
+                }),
+                _ => Err(Error::InvalidValue {
+                    reason: format!("`{}` is not a TOML inline table", args.value),
+                }),
+            }
+        }
+    }
+}
+
+pub fn run(args: SetArgs) -> Result<(), Error> {
+    let contents = fs::read_to_string(&args.file).map_err(|source| Error::Io {
+        path: args.file.clone(),
+        source,
+    })?;
+    let mut document = edit::parse_document(&contents, &args.file)?;
+
+    let new_value = edit::to_edit_value(coerce(&args)?);
+    let segments = path::parse(&args.key)?;
+    let (parents, last) = segments.split_at(segments.len() - 1);
+    let container = edit::navigate(&mut document, parents)?;
+    edit::set(container, &last[0], new_value)?;
+
+    let rendered = document.to_string();
+    fs::write(&args.file, &rendered).map_err(|source| Error::Io {
+        path: args.file.clone(),
+        source,
+    })?;
+
+    print!("{rendered}");
+    Ok(())
+}
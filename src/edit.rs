@@ -0,0 +1,146 @@
+//! Format-preserving document editing, backing `set` and `rm`.
+//!
+//! Unlike `get`, which only ever reads a document, `set` and `rm` rewrite
+//! the file on disk. We use `toml_edit` for that so comments, key ordering
+//! and blank lines survive the round trip instead of being normalized away.
+
+use std::path::Path;
+
+use toml_edit::{Array, Document, Item, Value};
+
+use crate::error::Error;
+use crate::path::Segment;
+
+pub fn parse_document(contents: &str, file: &Path) -> Result<Document, Error> {
+    contents.parse::<Document>().map_err(|err| Error::ParseFailure {
+        path: file.to_path_buf(),
+        reason: err.to_string(),
+    })
+}
+
+/// Walk a document following a key path, returning the item the last
+/// segment should read from or write into.
+///
+/// Only `Segment::Key` steps are supported along the way; indexing into an
+/// array is only meaningful as the final segment of a path.
+pub fn navigate<'a>(document: &'a mut Document, segments: &[Segment]) -> Result<&'a mut Item, Error> {
+    let mut item: &mut Item = document.as_item_mut();
+    for segment in segments {
+        match segment {
+            Segment::Key(key) => {
+                let table = item.as_table_like_mut().ok_or_else(|| Error::KeyNotFound {
+                    key: key.clone(),
+                })?;
+                item = table.get_mut(key).ok_or_else(|| Error::KeyNotFound {
+                    key: key.clone(),
+                })?;
+            }
+            Segment::Index(_) | Segment::Append => {
+                return Err(Error::BadKeySyntax {
+                    key: String::new(),
+                    reason: "array indexing is only supported as the last segment of a path"
+                        .to_string(),
+                })
+            }
+        }
+    }
+    Ok(item)
+}
+
+/// Write `value` at `last` within `container`, which must be the item
+/// returned by [`navigate`] for the path's parent segments.
+pub fn set(container: &mut Item, last: &Segment, value: Value) -> Result<(), Error> {
+    match last {
+        Segment::Key(key) => {
+            let table = container.as_table_like_mut().ok_or_else(|| Error::KeyNotFound {
+                key: key.clone(),
+            })?;
+            match table.get_mut(key).and_then(Item::as_value_mut) {
+                // Overwrite in place so the key's leading comments and the
+                // value's trailing comment are kept.
+                Some(existing) => {
+                    let decor = existing.decor().clone();
+                    *existing = value;
+                    *existing.decor_mut() = decor;
+                }
+                None => {
+                    table.insert(key, Item::Value(value));
+                }
+            }
+        }
+        Segment::Index(index) => {
+            let array = array_mut(container)?;
+            let len = array.len();
+            if *index >= len {
+                return Err(Error::IndexOutOfBounds { index: *index, len });
+            }
+            array.replace(*index, value);
+        }
+        Segment::Append => {
+            array_mut(container)?.push(value);
+        }
+    }
+    Ok(())
+}
+
+/// Remove the element named by `last` from `container`, which must be the
+/// item returned by [`navigate`] for the path's parent segments.
+pub fn remove(container: &mut Item, last: &Segment) -> Result<(), Error> {
+    match last {
+        Segment::Key(key) => {
+            let table = container.as_table_like_mut().ok_or_else(|| Error::KeyNotFound {
+                key: key.clone(),
+            })?;
+            table.remove(key).ok_or_else(|| Error::KeyNotFound {
+                key: key.clone(),
+            })?;
+        }
+        Segment::Index(index) => {
+            let array = array_mut(container)?;
+            let len = array.len();
+            if *index >= len {
+                return Err(Error::IndexOutOfBounds { index: *index, len });
+            }
+            array.remove(*index);
+        }
+        Segment::Append => {
+            return Err(Error::BadKeySyntax {
+                key: String::new(),
+                reason: "`[]` names a new element; it can't be removed".to_string(),
+            })
+        }
+    }
+    Ok(())
+}
+
+fn array_mut(container: &mut Item) -> Result<&mut Array, Error> {
+    container.as_array_mut().ok_or_else(|| Error::NotAnArray {
+        path: String::new(),
+    })
+}
+
+/// Convert a `toml::Value` (as produced by `set`'s `--type`/`--json`
+/// coercion) into the `toml_edit::Value` the format-preserving editor needs.
+pub fn to_edit_value(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::from(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => Value::from(f),
+        toml::Value::Boolean(b) => Value::from(b),
+        toml::Value::Datetime(d) => Value::from(d.to_string().parse::<toml_edit::Datetime>().unwrap()),
+        toml::Value::Array(arr) => {
+            let mut array = Array::new();
+            for element in arr {
+                array.push(to_edit_value(element));
+            }
+            Value::from(array)
+        }
+        toml::Value::Table(table) => {
+            let mut inline = toml_edit::InlineTable::new();
+            for (key, value) in table {
+                inline.insert(&key, to_edit_value(value));
+            }
+            Value::from(inline)
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+use std::path::PathBuf;
+
+/// Everything that can go wrong while resolving a key path against a TOML
+/// file on disk.
+///
+/// Each variant maps to a stable process exit code (see [`Error::exit_code`])
+/// so scripts driving this tool can branch on failure mode without having to
+/// parse the error message.
+#[derive(Debug)]
+pub enum Error {
+    /// The key path does not exist in the document.
+    KeyNotFound { key: String },
+    /// The key path argument itself could not be parsed.
+    BadKeySyntax { key: String, reason: String },
+    /// The file's contents are not valid TOML.
+    ParseFailure { path: PathBuf, reason: String },
+    /// Reading or writing the file failed.
+    Io { path: PathBuf, source: std::io::Error },
+    /// An index segment was used where the value isn't an array.
+    NotAnArray { path: String },
+    /// An index segment was out of bounds for the array it indexed.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// A `set` argument could not be coerced to the requested type.
+    InvalidValue { reason: String },
+}
+
+impl Error {
+    /// The process exit status to use when this error reaches `main`.
+    ///
+    /// 1 means "not found", 2 means "usage/syntax", 3 means "IO failure".
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::KeyNotFound { .. } => 1,
+            Error::BadKeySyntax { .. } => 2,
+            Error::ParseFailure { .. } => 2,
+            Error::Io { .. } => 3,
+            Error::NotAnArray { .. } => 2,
+            Error::IndexOutOfBounds { .. } => 1,
+            Error::InvalidValue { .. } => 2,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::KeyNotFound { key } => write!(f, "key not found: {key}"),
+            Error::BadKeySyntax { key, reason } => {
+                write!(f, "invalid key path `{key}`: {reason}")
+            }
+            Error::ParseFailure { path, reason } => {
+                write!(f, "failed to parse {}: {reason}", path.display())
+            }
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::NotAnArray { path } => write!(f, "`{path}` is not an array"),
+            Error::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} out of bounds (array has {len} elements)")
+            }
+            Error::InvalidValue { reason } => write!(f, "invalid value: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
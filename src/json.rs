@@ -0,0 +1,56 @@
+//! Conversions between `toml::Value` and `serde_json::Value`.
+
+use crate::error::Error;
+
+pub fn toml_to_json(value: &toml::Value) -> serde_json::Value {
+    match value {
+        toml::Value::String(s) => serde_json::Value::String(s.clone()),
+        toml::Value::Integer(i) => serde_json::Value::from(*i),
+        toml::Value::Float(f) => serde_json::Value::from(*f),
+        toml::Value::Boolean(b) => serde_json::Value::from(*b),
+        toml::Value::Datetime(d) => serde_json::Value::String(d.to_string()),
+        toml::Value::Array(arr) => serde_json::Value::Array(arr.iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => {
+            let mut map = serde_json::Map::new();
+            for (k, v) in table {
+                map.insert(k.clone(), toml_to_json(v));
+            }
+            serde_json::Value::Object(map)
+        }
+    }
+}
+
+/// Convert a parsed JSON value into the closest equivalent `toml::Value`.
+///
+/// JSON has no distinct "datetime" type, so datetimes can only be produced
+/// via `--type datetime`, never via `--json`.
+pub fn json_to_toml(value: serde_json::Value) -> Result<toml::Value, Error> {
+    match value {
+        serde_json::Value::Null => Err(Error::InvalidValue {
+            reason: "TOML has no null value".to_string(),
+        }),
+        serde_json::Value::Bool(b) => Ok(toml::Value::Boolean(b)),
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Ok(toml::Value::Integer(i))
+            } else if let Some(f) = n.as_f64() {
+                Ok(toml::Value::Float(f))
+            } else {
+                Err(Error::InvalidValue {
+                    reason: format!("number `{n}` doesn't fit in a TOML integer or float"),
+                })
+            }
+        }
+        serde_json::Value::String(s) => Ok(toml::Value::String(s)),
+        serde_json::Value::Array(arr) => Ok(toml::Value::Array(
+            arr.into_iter().map(json_to_toml).collect::<Result<_, _>>()?,
+        )),
+        serde_json::Value::Object(map) => {
+            let mut table = toml::value::Table::new();
+            for (k, v) in map {
+                table.insert(k, json_to_toml(v)?);
+            }
+            Ok(toml::Value::Table(table))
+        }
+    }
+}
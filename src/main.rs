@@ -0,0 +1,45 @@
+mod commands;
+mod edit;
+mod error;
+mod json;
+mod path;
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use commands::{get, rm, set};
+
+/// Get and set values in TOML files from the command line.
+#[derive(Parser)]
+#[command(name = "toml")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the value at a key path.
+    Get(get::GetArgs),
+    /// Write a value at a key path.
+    Set(set::SetArgs),
+    /// Remove a key, array element, or table.
+    Rm(rm::RmArgs),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Get(args) => get::run(args),
+        Command::Set(args) => set::run(args),
+        Command::Rm(args) => rm::run(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::from(err.exit_code() as u8)
+        }
+    }
+}
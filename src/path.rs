@@ -0,0 +1,164 @@
+use crate::error::Error;
+
+/// One component of a key path, e.g. `foo`, `bar` and `0` in `foo.bar[0]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    Key(String),
+    /// An array index, e.g. the `0` in `arr[0]`.
+    Index(usize),
+    /// A trailing `[]`, meaning "append a new element".
+    Append,
+}
+
+/// Split a key path into its component segments.
+///
+/// Honors TOML's quoted-key syntax, so `"quoted key".inner` splits into
+/// `["quoted key", "inner"]` rather than on every `.` in the string. Also
+/// understands array indexing and array-of-tables access, e.g.
+/// `foo.arr[0]` or `servers[2].host`.
+pub fn parse(path: &str) -> Result<Vec<Segment>, Error> {
+    let mut segments = Vec::new();
+    let mut chars = path.chars().peekable();
+
+    loop {
+        skip_spaces(&mut chars);
+        let key = if chars.peek() == Some(&'"') {
+            parse_quoted(path, &mut chars)?
+        } else {
+            parse_bare(&mut chars)
+        };
+        segments.push(Segment::Key(key));
+
+        while chars.peek() == Some(&'[') {
+            segments.push(parse_index(path, &mut chars)?);
+        }
+
+        skip_spaces(&mut chars);
+        match chars.next() {
+            None => break,
+            Some('.') => continue,
+            Some(c) => {
+                return Err(Error::BadKeySyntax {
+                    key: path.to_string(),
+                    reason: format!("unexpected character `{c}` after key"),
+                })
+            }
+        }
+    }
+
+    Ok(segments)
+}
+
+fn skip_spaces(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while chars.peek() == Some(&' ') {
+        chars.next();
+    }
+}
+
+fn parse_bare(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut key = String::new();
+    while let Some(&c) = chars.peek() {
+        if c == '.' || c == ' ' || c == '[' {
+            break;
+        }
+        key.push(c);
+        chars.next();
+    }
+    key
+}
+
+fn parse_quoted(
+    path: &str,
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+) -> Result<String, Error> {
+    chars.next(); // consume opening quote
+    let mut key = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(key),
+            Some(c) => key.push(c),
+            None => {
+                return Err(Error::BadKeySyntax {
+                    key: path.to_string(),
+                    reason: "unterminated quoted key".to_string(),
+                })
+            }
+        }
+    }
+}
+
+fn parse_index(
+    path: &str,
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+) -> Result<Segment, Error> {
+    chars.next(); // consume '['
+    let mut digits = String::new();
+    loop {
+        match chars.next() {
+            Some(']') => break,
+            Some(c) => digits.push(c),
+            None => {
+                return Err(Error::BadKeySyntax {
+                    key: path.to_string(),
+                    reason: "unterminated `[`".to_string(),
+                })
+            }
+        }
+    }
+    if digits.is_empty() {
+        return Ok(Segment::Append);
+    }
+    digits.parse::<usize>().map(Segment::Index).map_err(|_| Error::BadKeySyntax {
+        key: path.to_string(),
+        reason: format!("`{digits}` is not a valid array index"),
+    })
+}
+
+/// Walk a [`toml::Value`] following a parsed key path, returning a reference
+/// to the value it names.
+pub fn get<'a>(value: &'a toml::Value, segments: &[Segment]) -> Result<&'a toml::Value, Error> {
+    let mut current = value;
+    let mut seen = String::new();
+    for segment in segments {
+        describe(&mut seen, segment);
+        match segment {
+            Segment::Key(key) => {
+                let table = current.as_table().ok_or_else(|| Error::KeyNotFound {
+                    key: key.clone(),
+                })?;
+                current = table.get(key).ok_or_else(|| Error::KeyNotFound {
+                    key: key.clone(),
+                })?;
+            }
+            Segment::Index(index) => {
+                let array = current
+                    .as_array()
+                    .ok_or_else(|| Error::NotAnArray { path: seen.clone() })?;
+                current = array.get(*index).ok_or_else(|| Error::IndexOutOfBounds {
+                    index: *index,
+                    len: array.len(),
+                })?;
+            }
+            Segment::Append => {
+                return Err(Error::BadKeySyntax {
+                    key: seen.clone(),
+                    reason: "`[]` is only valid when setting a value".to_string(),
+                })
+            }
+        }
+    }
+    Ok(current)
+}
+
+fn describe(seen: &mut String, segment: &Segment) {
+    match segment {
+        Segment::Key(key) => {
+            if !seen.is_empty() {
+                seen.push('.');
+            }
+            seen.push_str(key);
+        }
+        Segment::Index(index) => seen.push_str(&format!("[{index}]")),
+        Segment::Append => seen.push_str("[]"),
+    }
+}
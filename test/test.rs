@@ -64,9 +64,17 @@ bare-Key_1 = "bare"  # another TOML comment
 dotted.a = "dotted-a"
 dotted . b = "dotted-b"
 
+arr = [1, 2, 3]
+
 [foo]
 x = "foo-x"
 y.yy = "foo-yy"
+
+[[product]]
+sku = "a"
+
+[[product]]
+sku = "b"
 "#;
 
 tomltest_get1!(get_string, "key", "value");
@@ -83,20 +91,42 @@ tomltest_get1!(get_dotted_key, "dotted.a", "dotted-a");
 tomltest_get1!(get_dotted_spaced_key, "dotted.b", "dotted-b");
 tomltest_get1!(get_nested, "foo.x", "foo-x");
 tomltest_get1!(get_nested_dotted, "foo.y.yy", "foo-yy");
-// TODO test `get` inside arrays and arrays of tables
+
+// Test array indexing and array-of-tables navigation.
+tomltest_get1!(get_array_element, "arr[1]", 2);
+tomltest_get1!(get_array_of_tables, "product[1].sku", "b");
+tomltest_get_err!(get_array_out_of_bounds, ["arr[10]"], "out of bounds");
+tomltest_get_err!(get_index_into_non_array, ["key[0]"], "not an array");
 
 tomltest_get!(get_string_raw, ["--raw", "key"], "value\n");
 // TODO test `get --raw` on non-strings
 
-// TODO test `get --output-toml`
+tomltest_get!(
+    get_output_toml_scalar,
+    ["--output-toml", "foo.x"],
+    "x = \"foo-x\"\n"
+);
+tomltest_get!(
+    get_output_toml_table,
+    ["--output-toml", "foo"],
+    "[foo]\nx = \"foo-x\"\n\n[foo.y]\nyy = \"foo-yy\"\n"
+);
+
+tomltest_get_err!(get_missing, ["nosuchkey"], "key not found");
 
-tomltest_get_err!(get_missing, ["nosuchkey"], "panicked"); // TODO should make error better
+tomltest!(get_missing_if_exists, |mut t: TestCaseState| {
+    t.write_file(INPUT);
+    t.cmd
+        .args(["get", &t.filename(), "nosuchkey", "--if-exists"]);
+    check_eq("", &t.expect_success());
+});
 
 tomltest!(set_string_existing, |mut t: TestCaseState| {
     let contents = r#"[a]
 b = "c"
 [x]
-y = "z""#;
+y = "z"
+"#;
     t.write_file(contents);
     t.cmd.args(["set", &t.filename(), "x.y", "new"]);
     let expected = r#"[a]
@@ -111,7 +141,8 @@ tomltest!(set_string, |mut t: TestCaseState| {
     let contents = r#"[a]
 b = "c"
 [x]
-y = "z""#;
+y = "z"
+"#;
     t.write_file(contents);
     t.cmd.args(["set", &t.filename(), "x.z", "123"]);
     let expected = r#"[a]
@@ -123,6 +154,84 @@ z = "123"
     check_eq(expected, &t.expect_success());
 });
 
+tomltest!(set_typed_int, |mut t: TestCaseState| {
+    t.write_file("x = 1\n");
+    t.cmd.args(["set", &t.filename(), "--type", "int", "x", "17"]);
+    check_eq("x = 17\n", &t.expect_success());
+});
+
+tomltest!(set_typed_bool, |mut t: TestCaseState| {
+    t.write_file("x = 1\n");
+    t.cmd.args(["set", &t.filename(), "--type", "bool", "x", "true"]);
+    check_eq("x = true\n", &t.expect_success());
+});
+
+tomltest!(set_typed_int_bad, |mut t: TestCaseState| {
+    t.write_file("x = 1\n");
+    t.cmd
+        .args(["set", &t.filename(), "--type", "int", "x", "not-a-number"]);
+    assert!(t.expect_error().contains("invalid value"));
+});
+
+tomltest!(set_json_array, |mut t: TestCaseState| {
+    t.write_file("[config]\n");
+    t.cmd
+        .args(["set", &t.filename(), "--json", "config.ports", "[80,443]"]);
+    check_eq("[config]\nports = [80, 443]\n", &t.expect_success());
+});
+
+tomltest!(set_array_index, |mut t: TestCaseState| {
+    let contents = "arr = [1, 2, 3]\n";
+    t.write_file(contents);
+    t.cmd.args(["set", &t.filename(), "arr[1]", "9"]);
+    let expected = "arr = [1, \"9\", 3]\n";
+    check_eq(expected, &t.expect_success());
+});
+
+tomltest!(set_array_append, |mut t: TestCaseState| {
+    let contents = "arr = [1, 2, 3]\n";
+    t.write_file(contents);
+    t.cmd.args(["set", &t.filename(), "arr[]", "9"]);
+    let expected = "arr = [1, 2, 3, \"9\"]\n";
+    check_eq(expected, &t.expect_success());
+});
+
+tomltest!(set_preserves_comments, |mut t: TestCaseState| {
+    let contents = "# leading comment\nkey = \"value\" # trailing comment\n";
+    t.write_file(contents);
+    t.cmd.args(["set", &t.filename(), "key", "new"]);
+    let expected = "# leading comment\nkey = \"new\" # trailing comment\n";
+    check_eq(expected, &t.expect_success());
+});
+
+tomltest!(rm_key, |mut t: TestCaseState| {
+    let contents = "[a]\nb = \"c\"\n[x]\ny = \"z\"\n";
+    t.write_file(contents);
+    t.cmd.args(["rm", &t.filename(), "x.y"]);
+    let expected = "[a]\nb = \"c\"\n[x]\n";
+    check_eq(expected, &t.expect_success());
+});
+
+tomltest!(rm_array_element, |mut t: TestCaseState| {
+    t.write_file("arr = [1, 2, 3]\n");
+    t.cmd.args(["rm", &t.filename(), "arr[1]"]);
+    check_eq("arr = [1, 3]\n", &t.expect_success());
+});
+
+tomltest!(rm_missing_key, |mut t: TestCaseState| {
+    t.write_file(INPUT);
+    t.cmd.args(["rm", &t.filename(), "nosuchkey"]);
+    assert!(t.expect_error().contains("key not found"));
+});
+
+tomltest!(rm_dry_run_leaves_file_untouched, |mut t: TestCaseState| {
+    let contents = "key = \"value\"\nother = 1\n";
+    t.write_file(contents);
+    t.cmd.args(["rm", &t.filename(), "key", "--dry-run"]);
+    check_eq("other = 1\n", &t.expect_success());
+    check_eq(contents, &fs::read_to_string(t.filename()).unwrap());
+});
+
 struct TestCaseState {
     cmd: process::Command,
     #[allow(dead_code)] // We keep the TempDir around to prolong its lifetime